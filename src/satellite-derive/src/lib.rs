@@ -0,0 +1,324 @@
+//! `#[derive(Validate)]` and `#[derive(Modify)]` for the `satellite`
+//! validation module.
+//!
+//! Annotate a struct's fields with `#[validate(...)]` attributes and derive
+//! `Validate` to check them all in one call:
+//!
+//! ```ignore
+//! #[derive(Validate, Modify)]
+//! struct Profile {
+//!     #[modify(trim, lowercase)]
+//!     #[validate(handle)]
+//!     handle: String,
+//!     #[validate(display_name)]
+//!     display_name: String,
+//!     #[validate(length(min = 1, max = 280))]
+//!     bio: String,
+//!     #[validate(custom = "validate_tag_date_struct")]
+//!     joined: TagDate,
+//! }
+//! ```
+//!
+//! Every field is checked even if an earlier one fails, and every failure is
+//! collected into a single `ValidationErrors` keyed by field name. Deriving
+//! both `Validate` and `Modify` also gets a struct
+//! `ValidateAndModify::validate_and_modify`, which sanitizes fields (in
+//! declaration order) before validating them.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+/// Resolves how the `satellite` crate should be referred to from generated
+/// code: `crate` when we're expanding inside `satellite` itself (where a
+/// crate can't name itself without this), or the renamed path a downstream
+/// `Cargo.toml` gave it otherwise.
+fn satellite_path() -> TokenStream2 {
+    match crate_name("satellite") {
+        Ok(FoundCrate::Itself) => quote!(crate),
+        Ok(FoundCrate::Name(name)) => {
+            let ident = Ident::new(&name, Span::call_site());
+            quote!(::#ident)
+        }
+        Err(_) => quote!(::satellite),
+    }
+}
+
+/// The built-in validators a field can be annotated with.
+enum FieldValidator {
+    Handle,
+    DisplayName,
+    Length { min: Option<i64>, max: Option<i64> },
+    Custom(String),
+}
+
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let satellite = satellite_path();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => panic!("Validate can only be derived for structs with named fields"),
+        },
+        _ => panic!("Validate can only be derived for structs"),
+    };
+
+    let mut checks = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_name = field_ident.to_string();
+
+        for validator in field_validators(field) {
+            let check = match validator {
+                FieldValidator::Handle => quote! {
+                    if let Err(error) = #satellite::validation::validate_handle(&self.#field_ident) {
+                        errors.add(#field_name, error);
+                    }
+                },
+                FieldValidator::DisplayName => quote! {
+                    if let Err(error) = #satellite::validation::validate_display_name(&self.#field_ident) {
+                        errors.add(#field_name, error);
+                    }
+                },
+                FieldValidator::Length { min, max } => {
+                    let min_check = min.map(|min| quote! {
+                        #satellite::validation::min_length(#min as usize)
+                    });
+                    let max_check = max.map(|max| quote! {
+                        #satellite::validation::max_length(#max as usize)
+                    });
+                    let combined = match (min_check, max_check) {
+                        (Some(min), Some(max)) => quote! {
+                            #satellite::validation::CustomValidator::check(
+                                &#satellite::validation::CustomValidator::and(#min, #max),
+                                &self.#field_ident,
+                            )
+                        },
+                        (Some(min), None) => quote! {
+                            #satellite::validation::CustomValidator::check(&#min, &self.#field_ident)
+                        },
+                        (None, Some(max)) => quote! {
+                            #satellite::validation::CustomValidator::check(&#max, &self.#field_ident)
+                        },
+                        (None, None) => quote! { Ok(()) },
+                    };
+                    quote! {
+                        if let Err(error) = #combined {
+                            errors.add(#field_name, error);
+                        }
+                    }
+                }
+                FieldValidator::Custom(path) => {
+                    let path = syn::parse_str::<syn::Path>(&path)
+                        .expect("custom validator must be a valid path");
+                    quote! {
+                        if let Err(error) = #path(&self.#field_ident) {
+                            errors.add(#field_name, error);
+                        }
+                    }
+                }
+            };
+
+            checks.push(check);
+        }
+    }
+
+    let expanded = quote! {
+        impl #satellite::validation::Validate for #name {
+            fn validate(&self) -> Result<(), #satellite::validation::ValidationErrors> {
+                let mut errors = #satellite::validation::ValidationErrors::new();
+
+                #(#checks)*
+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Parses the `#[validate(...)]` attributes attached to a single field.
+fn field_validators(field: &syn::Field) -> Vec<FieldValidator> {
+    let mut validators = Vec::new();
+
+    for attr in &field.attrs {
+        if !attr.path.is_ident("validate") {
+            continue;
+        }
+
+        let meta = attr.parse_meta().expect("invalid #[validate(...)] attribute");
+        let Meta::List(list) = meta else {
+            panic!("#[validate(...)] must take a list of validators");
+        };
+
+        for nested in list.nested {
+            validators.push(parse_validator(nested));
+        }
+    }
+
+    validators
+}
+
+/// The built-in modifiers a field can be annotated with.
+enum FieldModifier {
+    Trim,
+    Lowercase,
+    Uppercase,
+    Capitalize,
+    Custom(String),
+}
+
+#[proc_macro_derive(Modify, attributes(modify))]
+pub fn derive_modify(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let satellite = satellite_path();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => panic!("Modify can only be derived for structs with named fields"),
+        },
+        _ => panic!("Modify can only be derived for structs"),
+    };
+
+    let mut applications = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+
+        for modifier in field_modifiers(field) {
+            let application = match modifier {
+                FieldModifier::Trim => quote! {
+                    self.#field_ident = self.#field_ident.trim().to_string();
+                },
+                FieldModifier::Lowercase => quote! {
+                    self.#field_ident = self.#field_ident.to_lowercase();
+                },
+                FieldModifier::Uppercase => quote! {
+                    self.#field_ident = self.#field_ident.to_uppercase();
+                },
+                FieldModifier::Capitalize => quote! {
+                    self.#field_ident = {
+                        let mut chars = self.#field_ident.chars();
+                        match chars.next() {
+                            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                            None => String::new(),
+                        }
+                    };
+                },
+                FieldModifier::Custom(path) => {
+                    let path = syn::parse_str::<syn::Path>(&path)
+                        .expect("custom modifier must be a valid path");
+                    quote! {
+                        #path(&mut self.#field_ident);
+                    }
+                }
+            };
+
+            applications.push(application);
+        }
+    }
+
+    let expanded = quote! {
+        impl #satellite::validation::Modify for #name {
+            fn modify(&mut self) {
+                #(#applications)*
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Parses the `#[modify(...)]` attributes attached to a single field.
+fn field_modifiers(field: &syn::Field) -> Vec<FieldModifier> {
+    let mut modifiers = Vec::new();
+
+    for attr in &field.attrs {
+        if !attr.path.is_ident("modify") {
+            continue;
+        }
+
+        let meta = attr.parse_meta().expect("invalid #[modify(...)] attribute");
+        let Meta::List(list) = meta else {
+            panic!("#[modify(...)] must take a list of modifiers");
+        };
+
+        for nested in list.nested {
+            modifiers.push(parse_modifier(nested));
+        }
+    }
+
+    modifiers
+}
+
+fn parse_modifier(nested: NestedMeta) -> FieldModifier {
+    match nested {
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("trim") => FieldModifier::Trim,
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("lowercase") => {
+            FieldModifier::Lowercase
+        }
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("uppercase") => {
+            FieldModifier::Uppercase
+        }
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("capitalize") => {
+            FieldModifier::Capitalize
+        }
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("custom") => {
+            let Lit::Str(path) = &nv.lit else {
+                panic!("custom modifier must be a string path");
+            };
+            FieldModifier::Custom(path.value())
+        }
+        other => panic!("unsupported #[modify(...)] attribute: {:?}", other),
+    }
+}
+
+fn parse_validator(nested: NestedMeta) -> FieldValidator {
+    match nested {
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("handle") => FieldValidator::Handle,
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("display_name") => {
+            FieldValidator::DisplayName
+        }
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("length") => {
+            let mut min = None;
+            let mut max = None;
+
+            for item in list.nested {
+                if let NestedMeta::Meta(Meta::NameValue(nv)) = item {
+                    let Lit::Int(value) = &nv.lit else {
+                        panic!("length bounds must be integers");
+                    };
+                    let value = value.base10_parse::<i64>().expect("integer literal");
+
+                    if nv.path.is_ident("min") {
+                        min = Some(value);
+                    } else if nv.path.is_ident("max") {
+                        max = Some(value);
+                    }
+                }
+            }
+
+            FieldValidator::Length { min, max }
+        }
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("custom") => {
+            let Lit::Str(path) = &nv.lit else {
+                panic!("custom validator must be a string path");
+            };
+            FieldValidator::Custom(path.value())
+        }
+        other => panic!("unsupported #[validate(...)] attribute: {:?}", other),
+    }
+}
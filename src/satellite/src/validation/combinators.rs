@@ -0,0 +1,283 @@
+//! Composable validator building blocks
+//!
+//! [`CustomValidator`] lets new field types be validated by chaining small,
+//! reusable checks instead of writing a whole new module under
+//! `src/validation/`. The `#[derive(Validate)]` macro's `custom` attribute
+//! can point at anything implementing this trait, and the named functions in
+//! this module (`validate_handle`, etc.) are expected to delegate to these
+//! same primitives so there is one source of truth per check.
+
+use super::ValidationError;
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A reusable, composable check against a value of type `T`.
+pub trait CustomValidator<T> {
+    /// Checks `value`, returning an error describing why it's invalid.
+    fn check(&self, value: &T) -> Result<(), ValidationError>;
+
+    /// Chains `self` with `other`, running `other` only if `self` passes.
+    fn and<V>(self, other: V) -> And<Self, V>
+    where
+        Self: Sized,
+        V: CustomValidator<T>,
+    {
+        And(self, other)
+    }
+}
+
+/// The combinator returned by [`CustomValidator::and`].
+pub struct And<A, B>(A, B);
+
+impl<T, A, B> CustomValidator<T> for And<A, B>
+where
+    A: CustomValidator<T>,
+    B: CustomValidator<T>,
+{
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        self.0.check(value)?;
+        self.1.check(value)
+    }
+}
+
+/// Rejects strings shorter than `min` bytes.
+pub struct MinLength(pub usize);
+
+impl CustomValidator<String> for MinLength {
+    fn check(&self, value: &String) -> Result<(), ValidationError> {
+        if value.len() < self.0 {
+            return Err(ValidationError::new(
+                "length.too_short",
+                format!("must be at least {} characters long", self.0),
+            )
+            .with_param("min", self.0 as i64)
+            .with_param("value", value.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects strings longer than `max` bytes.
+pub struct MaxLength(pub usize);
+
+impl CustomValidator<String> for MaxLength {
+    fn check(&self, value: &String) -> Result<(), ValidationError> {
+        if value.len() > self.0 {
+            return Err(ValidationError::new(
+                "length.too_long",
+                format!("must be at most {} characters long", self.0),
+            )
+            .with_param("max", self.0 as i64)
+            .with_param("value", value.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects strings with fewer than `min` grapheme clusters.
+pub struct CharsMinLength(pub usize);
+
+impl CustomValidator<String> for CharsMinLength {
+    fn check(&self, value: &String) -> Result<(), ValidationError> {
+        if value.graphemes(true).count() < self.0 {
+            return Err(ValidationError::new(
+                "chars_length.too_short",
+                format!("must be at least {} characters long", self.0),
+            )
+            .with_param("min", self.0 as i64)
+            .with_param("value", value.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects strings with more than `max` grapheme clusters.
+pub struct CharsMaxLength(pub usize);
+
+impl CustomValidator<String> for CharsMaxLength {
+    fn check(&self, value: &String) -> Result<(), ValidationError> {
+        if value.graphemes(true).count() > self.0 {
+            return Err(ValidationError::new(
+                "chars_length.too_long",
+                format!("must be at most {} characters long", self.0),
+            )
+            .with_param("max", self.0 as i64)
+            .with_param("value", value.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects strings that don't match a regular expression.
+pub struct RegexValidator(pub Regex);
+
+impl CustomValidator<String> for RegexValidator {
+    fn check(&self, value: &String) -> Result<(), ValidationError> {
+        if !self.0.is_match(value) {
+            return Err(ValidationError::new(
+                "regex.no_match",
+                format!("must match the pattern {}", self.0.as_str()),
+            )
+            .with_param("pattern", self.0.as_str())
+            .with_param("value", value.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects values outside of `[min, max]`.
+pub struct Range<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T> CustomValidator<T> for Range<T>
+where
+    T: PartialOrd + std::fmt::Display + Copy + Into<serde_json::Value>,
+{
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        if *value < self.min || *value > self.max {
+            return Err(ValidationError::new(
+                "range.out_of_bounds",
+                format!("must be between {} and {}", self.min, self.max),
+            )
+            .with_param("min", self.min)
+            .with_param("max", self.max)
+            .with_param("value", *value));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects values that don't match any of `options`.
+pub struct OneOf<T> {
+    pub options: Vec<T>,
+}
+
+impl<T> CustomValidator<T> for OneOf<T>
+where
+    T: PartialEq + std::fmt::Debug + Clone + Into<serde_json::Value>,
+{
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        if !self.options.contains(value) {
+            return Err(ValidationError::new(
+                "one_of.invalid",
+                format!("must be one of {:?}", self.options),
+            )
+            .with_param("value", value.clone()));
+        }
+        Ok(())
+    }
+}
+
+/// Rejects strings shorter than `min` bytes.
+pub fn min_length(min: usize) -> MinLength {
+    MinLength(min)
+}
+
+/// Rejects strings longer than `max` bytes.
+pub fn max_length(max: usize) -> MaxLength {
+    MaxLength(max)
+}
+
+/// Rejects strings with fewer than `min` grapheme clusters.
+pub fn chars_min_length(min: usize) -> CharsMinLength {
+    CharsMinLength(min)
+}
+
+/// Rejects strings with more than `max` grapheme clusters.
+pub fn chars_max_length(max: usize) -> CharsMaxLength {
+    CharsMaxLength(max)
+}
+
+/// Rejects strings that don't match `pattern`.
+///
+/// Returns `Err` if `pattern` itself isn't a valid regular expression,
+/// rather than panicking, since the pattern is often caller-supplied rather
+/// than a compile-time constant.
+pub fn regex(pattern: &str) -> Result<RegexValidator, regex::Error> {
+    Ok(RegexValidator(Regex::new(pattern)?))
+}
+
+/// Rejects values outside of `[min, max]`.
+pub fn range<T>(min: T, max: T) -> Range<T> {
+    Range { min, max }
+}
+
+/// Rejects values that don't match any of `options`.
+pub fn one_of<T>(options: Vec<T>) -> OneOf<T> {
+    OneOf { options }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_length_rejects_short_strings() {
+        let error = min_length(5).check(&"abc".to_string()).unwrap_err();
+        assert_eq!(error.code, "length.too_short");
+        assert!(min_length(5).check(&"abcde".to_string()).is_ok());
+    }
+
+    #[test]
+    fn max_length_rejects_long_strings() {
+        let error = max_length(3).check(&"abcd".to_string()).unwrap_err();
+        assert_eq!(error.code, "length.too_long");
+        assert!(max_length(3).check(&"abc".to_string()).is_ok());
+    }
+
+    #[test]
+    fn chars_length_counts_grapheme_clusters_not_bytes() {
+        let five_emoji = "😀😀😀😀😀".to_string();
+        assert!(chars_min_length(5).check(&five_emoji).is_ok());
+        assert!(chars_max_length(5).check(&five_emoji).is_ok());
+        assert!(chars_max_length(4).check(&five_emoji).is_err());
+    }
+
+    #[test]
+    fn range_rejects_values_outside_bounds() {
+        assert!(range(1, 10).check(&5).is_ok());
+        assert_eq!(range(1, 10).check(&0).unwrap_err().code, "range.out_of_bounds");
+        assert_eq!(range(1, 10).check(&11).unwrap_err().code, "range.out_of_bounds");
+    }
+
+    #[test]
+    fn one_of_rejects_values_not_in_the_list() {
+        let validator = one_of(vec!["draft".to_string(), "published".to_string()]);
+        assert!(validator.check(&"draft".to_string()).is_ok());
+        assert_eq!(
+            validator.check(&"archived".to_string()).unwrap_err().code,
+            "one_of.invalid"
+        );
+    }
+
+    #[test]
+    fn regex_rejects_non_matching_strings() {
+        let validator = regex(r"^[a-z]+$").unwrap();
+        assert!(validator.check(&"abc".to_string()).is_ok());
+        assert_eq!(
+            validator.check(&"ABC".to_string()).unwrap_err().code,
+            "regex.no_match"
+        );
+    }
+
+    #[test]
+    fn regex_returns_err_for_an_invalid_pattern_instead_of_panicking() {
+        assert!(regex("(unterminated").is_err());
+    }
+
+    #[test]
+    fn and_short_circuits_on_the_first_failure() {
+        let validator = min_length(2).and(max_length(4));
+        assert!(validator.check(&"abc".to_string()).is_ok());
+        assert_eq!(
+            validator.check(&"a".to_string()).unwrap_err().code,
+            "length.too_short"
+        );
+        assert_eq!(
+            validator.check(&"abcde".to_string()).unwrap_err().code,
+            "length.too_long"
+        );
+    }
+}
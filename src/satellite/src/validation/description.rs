@@ -0,0 +1,47 @@
+//! Description format validation
+
+use super::ValidationError;
+
+const MAX_LENGTH: usize = 280;
+
+/// Validates a free-form description field (e.g. a profile bio or a tag note).
+///
+/// Descriptions are optional, so an empty string is valid, but anything
+/// provided must fit within [`MAX_LENGTH`] bytes.
+pub fn validate_description(description: &str) -> Result<(), ValidationError> {
+    if description.len() > MAX_LENGTH {
+        return Err(ValidationError::new(
+            "description.too_long",
+            format!("Description cannot be longer than {} characters", MAX_LENGTH),
+        )
+        .with_param("max", MAX_LENGTH as i64)
+        .with_param("value", description.to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_an_empty_description() {
+        assert!(validate_description("").is_ok());
+    }
+
+    #[test]
+    fn accepts_a_description_within_the_limit() {
+        assert!(validate_description("a short bio").is_ok());
+    }
+
+    #[test]
+    fn rejects_a_description_over_the_limit_with_its_code_and_params() {
+        let value = "a".repeat(MAX_LENGTH + 1);
+        let error = validate_description(&value).unwrap_err();
+
+        assert_eq!(error.code, "description.too_long");
+        assert_eq!(error.params.get("max"), Some(&serde_json::Value::from(MAX_LENGTH as i64)));
+        assert_eq!(error.params.get("value"), Some(&serde_json::Value::from(value)));
+    }
+}
@@ -0,0 +1,39 @@
+//! Display name validation
+
+use super::{
+    combinators::{chars_max_length, chars_min_length, max_length, min_length, CustomValidator},
+    ValidationError,
+};
+
+/// Byte-length bounds, to keep storage and wire payloads bounded.
+const MIN_LENGTH: usize = 1;
+const MAX_LENGTH: usize = 200;
+
+/// Grapheme-cluster bounds, so a handful of multi-byte emoji aren't
+/// penalized the way their byte length would suggest.
+const CHARS_MIN_LENGTH: usize = 1;
+const CHARS_MAX_LENGTH: usize = 50;
+
+/// Validates a display name.
+///
+/// A display name must be non-blank and fit within both the byte-length
+/// bounds (for storage) and the grapheme-cluster bounds (for what a user
+/// actually sees), since a display name full of multi-byte emoji can be
+/// well within a byte budget while still being far too long to read.
+pub fn validate_display_name(display_name: &str) -> Result<(), ValidationError> {
+    if display_name.trim().is_empty() {
+        return Err(ValidationError::new(
+            "display_name.empty",
+            "Display name cannot be empty",
+        ));
+    }
+
+    let value = display_name.to_string();
+
+    min_length(MIN_LENGTH).and(max_length(MAX_LENGTH)).check(&value)?;
+    chars_min_length(CHARS_MIN_LENGTH)
+        .and(chars_max_length(CHARS_MAX_LENGTH))
+        .check(&value)?;
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Structured validation errors
+//!
+//! A bare `Err(String)` can't be localized or matched on programmatically,
+//! so every validator in this module returns a [`ValidationError`] instead:
+//! a stable machine-readable `code`, a human-readable `message`, and
+//! whatever `params` describe the offending value (so a client can render
+//! "must be at most {max} characters" in any language, or highlight the
+//! exact character that failed).
+
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A single field-level validation failure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// A stable, dotted identifier such as `"handle.too_short"`, safe to
+    /// match on or use as an i18n lookup key.
+    pub code: &'static str,
+    /// A human-readable, English-language description of the failure.
+    pub message: String,
+    /// Structured detail about the failure, e.g. the offending value or the
+    /// limit it violated.
+    pub params: HashMap<String, Value>,
+}
+
+impl ValidationError {
+    /// Creates a new error with no params.
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            params: HashMap::new(),
+        }
+    }
+
+    /// Attaches a parameter to the error, e.g. the limit that was violated.
+    pub fn with_param(mut self, key: &str, value: impl Into<Value>) -> Self {
+        self.params.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+/// The errors collected from validating a struct, keyed by field name.
+///
+/// Unlike the individual validation functions, which return on the first
+/// failure, a [`super::Validate`] implementation collects every field's
+/// failures so an API layer can report all of them at once.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ValidationErrors(pub HashMap<String, Vec<ValidationError>>);
+
+impl ValidationErrors {
+    /// Creates an empty set of errors.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Records a failure for `field`.
+    pub fn add(&mut self, field: &str, error: ValidationError) {
+        self.0.entry(field.to_string()).or_default().push(error);
+    }
+
+    /// Returns `true` if no field has recorded a failure.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_param_attaches_params_without_losing_earlier_ones() {
+        let error = ValidationError::new("length.too_long", "too long")
+            .with_param("max", 10i64)
+            .with_param("value", "abc".to_string());
+
+        assert_eq!(error.code, "length.too_long");
+        assert_eq!(error.params.get("max"), Some(&Value::from(10i64)));
+        assert_eq!(error.params.get("value"), Some(&Value::from("abc")));
+    }
+
+    #[test]
+    fn validation_errors_starts_empty() {
+        assert!(ValidationErrors::new().is_empty());
+    }
+
+    #[test]
+    fn validation_errors_add_groups_by_field_and_preserves_order() {
+        let mut errors = ValidationErrors::new();
+        errors.add("handle", ValidationError::new("handle.invalid_char", "bad"));
+        errors.add("handle", ValidationError::new("handle.invalid_length", "bad"));
+        errors.add("bio", ValidationError::new("length.too_long", "bad"));
+
+        assert!(!errors.is_empty());
+        assert_eq!(errors.0.get("handle").unwrap().len(), 2);
+        assert_eq!(errors.0.get("handle").unwrap()[0].code, "handle.invalid_char");
+        assert_eq!(errors.0.get("handle").unwrap()[1].code, "handle.invalid_length");
+        assert_eq!(errors.0.get("bio").unwrap()[0].code, "length.too_long");
+    }
+}
@@ -7,9 +7,21 @@
  * - Tag name validation
  * - Time period validation
  * - Description format validation
- * 
+ * - Secret/password strength validation
+ *
  * These validation functions are used throughout the codebase to ensure
  * data integrity and consistency.
+ *
+ * Structs can also derive [`Validate`] and annotate their fields with
+ * `#[validate(...)]` attributes to run all of the above in one call instead
+ * of invoking each function by hand. Pairing that with `#[derive(Modify)]`
+ * and `#[modify(...)]` attributes sanitizes fields (trimming, case folding,
+ * ...) before they're validated; `ValidateAndModify::validate_and_modify`
+ * runs both passes in one step.
+ *
+ * Every validator returns a [`ValidationError`] with a stable machine-
+ * readable `code` and structured `params`, rather than a bare string, so
+ * callers can localize messages or highlight the exact failing field.
  */
 
 mod description;
@@ -17,20 +29,27 @@ mod display_name;
 mod validate_handle;
 pub mod ulid_timestamp_validate;
 mod validate_tag_date;
+mod validate_trait;
+mod modify;
+mod combinators;
+mod error;
+mod validate_secret;
 
 pub use description::validate_description;
 pub use display_name::validate_display_name;
 pub use validate_handle::validate_handle;
-pub use validate_tag_date::validate_tag_date_struct;
-pub use ulid_timestamp_validate::validate_timestamp_component;
-
-// TODO: As validation functions are moved from utils to this module,
-// add the following structure:
-
-// mod validate_name;  // For username, display name, tag name validations
-// mod validate_time;  // For time period validations
-// mod validate_format; // For description format validations
+pub use validate_tag_date::{validate_tag_date_struct, TagDate};
+pub use ulid_timestamp_validate::{
+    validate_timestamp_component, validate_timestamp_monotonic, validate_timestamp_range,
+    TimestampBounds,
+};
+pub use validate_secret::validate_secret_strength;
+pub use validate_trait::Validate;
+pub use modify::{Modify, ValidateAndModify};
+pub use combinators::{
+    chars_max_length, chars_min_length, max_length, min_length, one_of, range, regex,
+    CustomValidator,
+};
+pub use error::{ValidationError, ValidationErrors};
 
-// pub use validate_name::{validate_username, validate_display_name, validate_tag_name};
-// pub use validate_time::validate_time_periods;
-// pub use validate_format::validate_description; 
\ No newline at end of file
+pub use satellite_derive::{Validate, Modify};
\ No newline at end of file
@@ -0,0 +1,30 @@
+//! The `Modify` trait
+//!
+//! Implementing [`Modify`] (usually via `#[derive(Modify)]`) lets a struct
+//! sanitize its own fields before [`Validate::validate`](super::Validate) is
+//! run, e.g. trimming whitespace or lowercasing a handle so it doesn't get
+//! rejected for formatting that a caller shouldn't have to think about.
+
+use super::{Validate, ValidationErrors};
+
+/// A type that can normalize its own fields in place, typically via
+/// `#[derive(Modify)]`.
+pub trait Modify {
+    /// Applies every field-level modifier, in declaration order.
+    fn modify(&mut self);
+}
+
+/// Runs [`Modify::modify`] followed by [`Validate::validate`] in one call.
+///
+/// Blanket-implemented for any type that derives both `Modify` and
+/// `Validate`, so callers get a cleaned, validated value without having to
+/// remember to call the two passes in order themselves.
+pub trait ValidateAndModify: Modify + Validate {
+    /// Sanitizes `self`, then validates the result.
+    fn validate_and_modify(&mut self) -> Result<(), ValidationErrors> {
+        self.modify();
+        self.validate()
+    }
+}
+
+impl<T: Modify + Validate> ValidateAndModify for T {}
@@ -0,0 +1,316 @@
+//! ULID timestamp component validation
+//!
+//! Record identifiers in this crate are ULIDs, which embed a 48-bit
+//! millisecond timestamp in their first 10 characters. Validating that
+//! component lets us reject keys that aren't well-formed ULIDs before we
+//! ever try to decode them. [`validate_timestamp_range`] and
+//! [`validate_timestamp_monotonic`] decode it further, so a caller that
+//! creates or accepts tag/event records can bound- or order-check their
+//! timestamps and keep them from being back- or forward-dated to game
+//! scoring windows.
+
+use super::ValidationError;
+
+const ULID_TIMESTAMP_LENGTH: usize = 10;
+const CROCKFORD_BASE32: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The window a ULID's embedded timestamp must fall within.
+pub struct TimestampBounds {
+    /// The earliest timestamp (milliseconds since the Unix epoch) allowed.
+    pub not_before: u64,
+    /// The latest timestamp (milliseconds since the Unix epoch) allowed.
+    pub not_after: u64,
+    /// How far (in milliseconds) a timestamp may sit ahead of the current
+    /// clock before it's treated as forged.
+    pub allow_future_skew: u64,
+}
+
+/// Validates the timestamp component (the first 10 characters) of a ULID.
+pub fn validate_timestamp_component(ulid: &str) -> Result<(), ValidationError> {
+    if ulid.len() < ULID_TIMESTAMP_LENGTH {
+        return Err(ValidationError::new(
+            "ulid.too_short",
+            "ULID is too short to contain a timestamp component",
+        )
+        .with_param("value", ulid.to_string()));
+    }
+
+    let timestamp_component = &ulid[..ULID_TIMESTAMP_LENGTH];
+
+    if !timestamp_component
+        .chars()
+        .all(|c| CROCKFORD_BASE32.contains(c.to_ascii_uppercase()))
+    {
+        return Err(ValidationError::new(
+            "ulid.invalid_timestamp_component",
+            "ULID timestamp component contains invalid characters",
+        )
+        .with_param("value", timestamp_component.to_string()));
+    }
+
+    // A 48-bit millisecond timestamp only needs the first 2 of the 50 bits
+    // encoded by 10 base32 characters, so the leading character can only be
+    // one of the first 8 Crockford symbols (0-7); anything higher decodes to
+    // a timestamp outside the representable range.
+    let leading_char = timestamp_component.chars().next().unwrap().to_ascii_uppercase();
+    if !('0'..='7').contains(&leading_char) {
+        return Err(ValidationError::new(
+            "ulid.timestamp_overflow",
+            "ULID timestamp component overflows the 48-bit timestamp range",
+        )
+        .with_param("value", timestamp_component.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Decodes the millisecond timestamp embedded in a ULID's first 10
+/// characters.
+fn decode_timestamp_ms(ulid: &str) -> Result<u64, ValidationError> {
+    validate_timestamp_component(ulid)?;
+
+    let mut timestamp_ms: u64 = 0;
+    for c in ulid[..ULID_TIMESTAMP_LENGTH].chars() {
+        let digit = CROCKFORD_BASE32
+            .find(c.to_ascii_uppercase())
+            .expect("validated by validate_timestamp_component") as u64;
+        timestamp_ms = (timestamp_ms << 5) | digit;
+    }
+
+    Ok(timestamp_ms)
+}
+
+/// The current time, in milliseconds since the Unix epoch.
+fn now_ms() -> u64 {
+    ic_cdk::api::time() / 1_000_000
+}
+
+/// Validates that a ULID's embedded timestamp falls within `bounds`,
+/// rejecting timestamps more than `bounds.allow_future_skew` ahead of the
+/// current clock even if they're still inside `[not_before, not_after]`.
+pub fn validate_timestamp_range(ulid: &str, bounds: &TimestampBounds) -> Result<(), ValidationError> {
+    validate_timestamp_range_at(ulid, bounds, now_ms())
+}
+
+/// The guts of [`validate_timestamp_range`], taking the current time as a
+/// parameter so the future-skew check can be exercised deterministically in
+/// tests instead of depending on the real clock.
+fn validate_timestamp_range_at(
+    ulid: &str,
+    bounds: &TimestampBounds,
+    now: u64,
+) -> Result<(), ValidationError> {
+    let timestamp_ms = decode_timestamp_ms(ulid)?;
+
+    if timestamp_ms < bounds.not_before || timestamp_ms > bounds.not_after {
+        return Err(ValidationError::new(
+            "ulid.timestamp_out_of_bounds",
+            format!(
+                "ULID timestamp {} is outside the allowed range [{}, {}]",
+                timestamp_ms, bounds.not_before, bounds.not_after
+            ),
+        )
+        .with_param("timestamp_ms", timestamp_ms as i64)
+        .with_param("not_before", bounds.not_before as i64)
+        .with_param("not_after", bounds.not_after as i64));
+    }
+
+    if timestamp_ms > now + bounds.allow_future_skew {
+        return Err(ValidationError::new(
+            "ulid.timestamp_forged_future",
+            format!(
+                "ULID timestamp {} is more than {}ms ahead of the current time {}",
+                timestamp_ms, bounds.allow_future_skew, now
+            ),
+        )
+        .with_param("timestamp_ms", timestamp_ms as i64)
+        .with_param("now_ms", now as i64)
+        .with_param("allow_future_skew_ms", bounds.allow_future_skew as i64));
+    }
+
+    Ok(())
+}
+
+/// Validates that a ULID's embedded timestamp does not go backwards
+/// relative to `previous_timestamp_ms`, so records can't be inserted
+/// out of order to back-date them into an already-scored window.
+pub fn validate_timestamp_monotonic(
+    ulid: &str,
+    previous_timestamp_ms: u64,
+) -> Result<(), ValidationError> {
+    let timestamp_ms = decode_timestamp_ms(ulid)?;
+
+    if timestamp_ms < previous_timestamp_ms {
+        return Err(ValidationError::new(
+            "ulid.timestamp_not_monotonic",
+            format!(
+                "ULID timestamp {} is earlier than the previously seen timestamp {}",
+                timestamp_ms, previous_timestamp_ms
+            ),
+        )
+        .with_param("timestamp_ms", timestamp_ms as i64)
+        .with_param("previous_timestamp_ms", previous_timestamp_ms as i64));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a syntactically valid ULID with the given millisecond
+    /// timestamp encoded in its first 10 characters and an arbitrary, valid
+    /// randomness suffix.
+    fn ulid_with_timestamp_ms(timestamp_ms: u64) -> String {
+        assert!(timestamp_ms < (1u64 << 48), "timestamp must fit in 48 bits");
+
+        let mut timestamp_chars = ['0'; ULID_TIMESTAMP_LENGTH];
+        let mut remaining = timestamp_ms;
+        for i in (0..ULID_TIMESTAMP_LENGTH).rev() {
+            let digit = (remaining & 0x1F) as usize;
+            timestamp_chars[i] = CROCKFORD_BASE32.chars().nth(digit).unwrap();
+            remaining >>= 5;
+        }
+
+        let timestamp_component: String = timestamp_chars.iter().collect();
+        format!("{}0000000000000000", timestamp_component)
+    }
+
+    #[test]
+    fn decode_timestamp_ms_round_trips() {
+        for timestamp_ms in [0u64, 1, 1_700_000_000_000, (1u64 << 48) - 1] {
+            let ulid = ulid_with_timestamp_ms(timestamp_ms);
+            assert_eq!(decode_timestamp_ms(&ulid).unwrap(), timestamp_ms);
+        }
+    }
+
+    #[test]
+    fn validate_timestamp_component_rejects_short_input() {
+        assert_eq!(
+            validate_timestamp_component("123").unwrap_err().code,
+            "ulid.too_short"
+        );
+    }
+
+    #[test]
+    fn validate_timestamp_component_rejects_invalid_characters() {
+        let ulid = "ILOU000000".to_string() + "0000000000000000";
+        assert_eq!(
+            validate_timestamp_component(&ulid).unwrap_err().code,
+            "ulid.invalid_timestamp_component"
+        );
+    }
+
+    #[test]
+    fn validate_timestamp_component_rejects_leading_char_above_7() {
+        // '8' is a valid Crockford base32 symbol but would decode a 48-bit
+        // timestamp out of its representable range.
+        let ulid = "8000000000".to_string() + "0000000000000000";
+        assert_eq!(
+            validate_timestamp_component(&ulid).unwrap_err().code,
+            "ulid.timestamp_overflow"
+        );
+    }
+
+    #[test]
+    fn validate_timestamp_component_accepts_leading_char_0_through_7() {
+        for timestamp_ms in [0u64, 1, 1_700_000_000_000] {
+            let ulid = ulid_with_timestamp_ms(timestamp_ms);
+            assert!(validate_timestamp_component(&ulid).is_ok());
+        }
+    }
+
+    #[test]
+    fn validate_timestamp_range_accepts_timestamp_inside_bounds() {
+        let bounds = TimestampBounds {
+            not_before: 1_000,
+            not_after: 2_000,
+            allow_future_skew: 0,
+        };
+        let ulid = ulid_with_timestamp_ms(1_500);
+        assert!(validate_timestamp_range_at(&ulid, &bounds, 1_500).is_ok());
+    }
+
+    #[test]
+    fn validate_timestamp_range_rejects_timestamp_before_not_before() {
+        let bounds = TimestampBounds {
+            not_before: 1_000,
+            not_after: 2_000,
+            allow_future_skew: 0,
+        };
+        let ulid = ulid_with_timestamp_ms(500);
+        assert_eq!(
+            validate_timestamp_range_at(&ulid, &bounds, 500)
+                .unwrap_err()
+                .code,
+            "ulid.timestamp_out_of_bounds"
+        );
+    }
+
+    #[test]
+    fn validate_timestamp_range_rejects_timestamp_after_not_after() {
+        let bounds = TimestampBounds {
+            not_before: 1_000,
+            not_after: 2_000,
+            allow_future_skew: 10_000,
+        };
+        let ulid = ulid_with_timestamp_ms(2_500);
+        assert_eq!(
+            validate_timestamp_range_at(&ulid, &bounds, 2_500)
+                .unwrap_err()
+                .code,
+            "ulid.timestamp_out_of_bounds"
+        );
+    }
+
+    #[test]
+    fn validate_timestamp_range_rejects_timestamp_beyond_future_skew() {
+        let bounds = TimestampBounds {
+            not_before: 0,
+            not_after: u64::MAX,
+            allow_future_skew: 1_000,
+        };
+        let ulid = ulid_with_timestamp_ms(5_000);
+        // now = 3_000, so 5_000 is 2_000ms ahead: beyond the 1_000ms skew.
+        assert_eq!(
+            validate_timestamp_range_at(&ulid, &bounds, 3_000)
+                .unwrap_err()
+                .code,
+            "ulid.timestamp_forged_future"
+        );
+    }
+
+    #[test]
+    fn validate_timestamp_range_accepts_timestamp_within_future_skew() {
+        let bounds = TimestampBounds {
+            not_before: 0,
+            not_after: u64::MAX,
+            allow_future_skew: 1_000,
+        };
+        let ulid = ulid_with_timestamp_ms(3_500);
+        // now = 3_000, so 3_500 is only 500ms ahead: within the 1_000ms skew.
+        assert!(validate_timestamp_range_at(&ulid, &bounds, 3_000).is_ok());
+    }
+
+    #[test]
+    fn validate_timestamp_monotonic_accepts_later_timestamp() {
+        let ulid = ulid_with_timestamp_ms(2_000);
+        assert!(validate_timestamp_monotonic(&ulid, 1_000).is_ok());
+    }
+
+    #[test]
+    fn validate_timestamp_monotonic_accepts_equal_timestamp() {
+        let ulid = ulid_with_timestamp_ms(1_000);
+        assert!(validate_timestamp_monotonic(&ulid, 1_000).is_ok());
+    }
+
+    #[test]
+    fn validate_timestamp_monotonic_rejects_earlier_timestamp() {
+        let ulid = ulid_with_timestamp_ms(500);
+        assert_eq!(
+            validate_timestamp_monotonic(&ulid, 1_000).unwrap_err().code,
+            "ulid.timestamp_not_monotonic"
+        );
+    }
+}
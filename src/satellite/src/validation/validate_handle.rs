@@ -0,0 +1,201 @@
+//! Handle (username) validation
+
+use super::ValidationError;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+const MIN_LENGTH: usize = 3;
+const MAX_LENGTH: usize = 20;
+
+/// A coarse Unicode script grouping, just enough to tell whether a handle
+/// mixes scripts in a way that enables look-alike impersonation.
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Script {
+    Latin,
+    Cyrillic,
+    Greek,
+    Other,
+}
+
+fn script_of(c: char) -> Script {
+    match c {
+        'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' => Script::Latin,
+        '\u{0400}'..='\u{04FF}' => Script::Cyrillic,
+        '\u{0370}'..='\u{03FF}' => Script::Greek,
+        _ => Script::Other,
+    }
+}
+
+/// Codepoints outside of `a-z0-9_-` that are visually indistinguishable from
+/// an ASCII letter already valid in a handle. Not exhaustive, but covers the
+/// Cyrillic and Greek homoglyphs most commonly used for handle squatting.
+const CONFUSABLES: &[(char, char)] = &[
+    ('\u{0430}', 'a'), // CYRILLIC SMALL LETTER A
+    ('\u{0435}', 'e'), // CYRILLIC SMALL LETTER IE
+    ('\u{043E}', 'o'), // CYRILLIC SMALL LETTER O
+    ('\u{0440}', 'p'), // CYRILLIC SMALL LETTER ER
+    ('\u{0441}', 'c'), // CYRILLIC SMALL LETTER ES
+    ('\u{0443}', 'y'), // CYRILLIC SMALL LETTER U
+    ('\u{0445}', 'x'), // CYRILLIC SMALL LETTER HA
+    ('\u{03BF}', 'o'), // GREEK SMALL LETTER OMICRON
+    ('\u{0391}', 'a'), // GREEK CAPITAL LETTER ALPHA
+];
+
+fn confusable_target(c: char) -> Option<char> {
+    CONFUSABLES
+        .iter()
+        .find(|(confusable, _)| *confusable == c)
+        .map(|(_, target)| *target)
+}
+
+/// Validates a user handle.
+///
+/// A handle must be between [`MIN_LENGTH`] and [`MAX_LENGTH`] grapheme
+/// clusters long, and may only contain ASCII lowercase letters, digits,
+/// underscores and hyphens — every non-ASCII handle is rejected regardless
+/// of script. The confusable and mixed-script checks run *before* that
+/// ASCII gate purely so a look-alike attempt (e.g. Cyrillic `а` in place of
+/// Latin `a`) is rejected with a specific `handle.confusable_char` /
+/// `handle.mixed_script` code instead of the generic `handle.invalid_char`,
+/// since flagging impersonation attempts distinctly matters for a
+/// reputation system where handle uniqueness is the whole point — not to
+/// permit a wider character set than ASCII.
+pub fn validate_handle(handle: &str) -> Result<(), ValidationError> {
+    let normalized: String = handle.nfkc().collect();
+
+    let length = normalized.graphemes(true).count();
+    if length < MIN_LENGTH || length > MAX_LENGTH {
+        return Err(ValidationError::new(
+            "handle.invalid_length",
+            format!(
+                "Handle must be between {} and {} characters long",
+                MIN_LENGTH, MAX_LENGTH
+            ),
+        )
+        .with_param("min", MIN_LENGTH as i64)
+        .with_param("max", MAX_LENGTH as i64)
+        .with_param("value", normalized.clone()));
+    }
+
+    if let Some(c) = normalized.chars().find(|c| confusable_target(*c).is_some()) {
+        let target = confusable_target(c).unwrap();
+        return Err(ValidationError::new(
+            "handle.confusable_char",
+            format!(
+                "Handle contains the confusable character {:?}, which looks like {:?}",
+                c, target
+            ),
+        )
+        .with_param("codepoint", c.to_string())
+        .with_param("looks_like", target.to_string()));
+    }
+
+    let mut seen_script: Option<Script> = None;
+    for c in normalized.chars() {
+        let script = script_of(c);
+        if script == Script::Other {
+            return Err(ValidationError::new(
+                "handle.invalid_char",
+                format!("Handle contains the disallowed character {:?}", c),
+            )
+            .with_param("codepoint", c.to_string()));
+        }
+
+        match seen_script {
+            None => seen_script = Some(script),
+            Some(previous) if previous != script => {
+                return Err(ValidationError::new(
+                    "handle.mixed_script",
+                    format!(
+                        "Handle mixes scripts at character {:?}, which is not allowed",
+                        c
+                    ),
+                )
+                .with_param("codepoint", c.to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    if !normalized
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+    {
+        return Err(ValidationError::new(
+            "handle.invalid_char",
+            "Handle can only contain lowercase letters, digits, underscores and hyphens",
+        )
+        .with_param("value", normalized.clone()));
+    }
+
+    if normalized.starts_with('-') || normalized.starts_with('_') {
+        return Err(ValidationError::new(
+            "handle.invalid_start",
+            "Handle cannot start with a hyphen or underscore",
+        )
+        .with_param("value", normalized));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_a_well_formed_handle() {
+        assert!(validate_handle("jane_doe-92").is_ok());
+    }
+
+    #[test]
+    fn nfkc_normalizes_before_validating() {
+        // U+FF41 FULLWIDTH LATIN SMALL LETTER A NFKC-normalizes to 'a'.
+        assert!(validate_handle("\u{FF41}lice").is_ok());
+    }
+
+    #[test]
+    fn confusable_char_is_rejected_with_its_own_code_not_invalid_char() {
+        // A lone Cyrillic 'а' (U+0430) confusable with Latin 'a', surrounded
+        // by otherwise-valid ASCII. It's still rejected either way since
+        // handles are ASCII-only, but the code should name the reason
+        // precisely instead of falling back to the generic invalid_char.
+        let error = validate_handle("\u{0430}lice").unwrap_err();
+        assert_eq!(error.code, "handle.confusable_char");
+    }
+
+    #[test]
+    fn mixed_script_is_rejected_with_its_own_code() {
+        // Cyrillic 'б' (U+0431) isn't in the confusables table, so mixing it
+        // with Latin characters should be caught by the script-mixing check
+        // rather than the confusable check.
+        let error = validate_handle("a\u{0431}c").unwrap_err();
+        assert_eq!(error.code, "handle.mixed_script");
+    }
+
+    #[test]
+    fn non_ascii_non_confusable_char_falls_back_to_invalid_char() {
+        let error = validate_handle("h\u{00E9}llo").unwrap_err(); // 'é'
+        assert_eq!(error.code, "handle.invalid_char");
+    }
+
+    #[test]
+    fn rejects_too_short_and_too_long() {
+        assert_eq!(validate_handle("ab").unwrap_err().code, "handle.invalid_length");
+        assert_eq!(
+            validate_handle(&"a".repeat(MAX_LENGTH + 1)).unwrap_err().code,
+            "handle.invalid_length"
+        );
+    }
+
+    #[test]
+    fn rejects_leading_hyphen_or_underscore() {
+        assert_eq!(validate_handle("-abc").unwrap_err().code, "handle.invalid_start");
+        assert_eq!(validate_handle("_abc").unwrap_err().code, "handle.invalid_start");
+    }
+
+    #[test]
+    fn rejects_uppercase_ascii() {
+        assert_eq!(validate_handle("Abcdef").unwrap_err().code, "handle.invalid_char");
+    }
+}
@@ -0,0 +1,372 @@
+//! Password / secret strength validation
+//!
+//! Estimates how many guesses an attacker would need to crack a secret, the
+//! way [zxcvbn](https://github.com/dropbox/zxcvbn) does: break the input
+//! into overlapping "weak" patterns (dictionary words, keyboard walks,
+//! repeats, sequences, dates), find the cheapest way to cover the whole
+//! string with those patterns plus brute-force guessing for whatever's left
+//! over, and convert the resulting guess count into a 0-4 score via
+//! log10 thresholds. This rounds out the validation suite for any
+//! auth/recovery-phrase fields the reputation service needs.
+
+use super::ValidationError;
+
+/// A handful of extremely common passwords. Real deployments of this check
+/// should swap this in for a proper frequency-ranked dictionary; this list
+/// only needs to catch the worst offenders.
+const COMMON_PASSWORDS: &[&str] = &[
+    "password", "123456", "123456789", "qwerty", "letmein", "admin", "welcome",
+    "monkey", "dragon", "iloveyou", "football", "baseball", "trustno1",
+];
+
+/// A small set of common first names, used for the "name" dictionary match.
+const COMMON_NAMES: &[&str] = &[
+    "james", "john", "robert", "michael", "mary", "jennifer", "linda", "david",
+    "sarah", "emma",
+];
+
+const QWERTY_ROWS: &[&str] = &["qwertyuiop", "asdfghjkl", "zxcvbnm", "1234567890"];
+
+/// A weak pattern identified in the input, along with how many guesses it
+/// would take to find it.
+#[derive(Debug, Clone)]
+struct Match {
+    pattern: &'static str,
+    token: String,
+    start: usize,
+    end: usize,
+    guesses: f64,
+}
+
+/// Finds every dictionary, sequence, repeat, keyboard-walk and date match in
+/// `input`, along with the length (in lowercased characters) those matches'
+/// `start`/`end` offsets are expressed in. Matches may overlap;
+/// [`cheapest_cover`] picks a non-overlapping subset that minimizes the
+/// total guess count.
+///
+/// Matching is done against a lowercased copy so e.g. "Password" still
+/// triggers the "password" dictionary entry; the returned length is that
+/// lowercased copy's character count rather than `input`'s, since case
+/// folding can change how many characters a string has (e.g. "İ" lowercases
+/// to two characters) and callers must cover exactly as many positions as
+/// these offsets were computed against.
+fn find_matches(input: &str) -> (Vec<Match>, usize) {
+    let lower: Vec<char> = input.to_lowercase().chars().collect();
+    let mut matches = Vec::new();
+
+    for (dictionary, base_guesses) in [(COMMON_PASSWORDS, 10.0), (COMMON_NAMES, 20.0)] {
+        for &word in dictionary {
+            let word_chars: Vec<char> = word.chars().collect();
+            if word_chars.is_empty() || word_chars.len() > lower.len() {
+                continue;
+            }
+            for start in 0..=(lower.len() - word_chars.len()) {
+                let end = start + word_chars.len();
+                if lower[start..end] == word_chars[..] {
+                    matches.push(Match {
+                        pattern: "dictionary",
+                        token: word.to_string(),
+                        start,
+                        end,
+                        guesses: base_guesses,
+                    });
+                }
+            }
+        }
+    }
+
+    // Repeats: a single character repeated 3 or more times in a row.
+    let mut i = 0;
+    while i < lower.len() {
+        let mut j = i + 1;
+        while j < lower.len() && lower[j] == lower[i] {
+            j += 1;
+        }
+        if j - i >= 3 {
+            matches.push(Match {
+                pattern: "repeat",
+                token: lower[i..j].iter().collect(),
+                start: i,
+                end: j,
+                guesses: (j - i) as f64 * 2.0,
+            });
+        }
+        i = j.max(i + 1);
+    }
+
+    // Sequences: 3+ character runs that are consecutive ascending/descending
+    // codepoints (e.g. "abcd", "4321") or a run along a keyboard row.
+    if lower.len() >= 3 {
+        for start in 0..=(lower.len() - 3) {
+            let mut end = start + 1;
+            let ascending = lower[start + 1] as i32 - lower[start] as i32 == 1;
+            let descending = lower[start] as i32 - lower[start + 1] as i32 == 1;
+            if ascending || descending {
+                let step: i32 = if ascending { 1 } else { -1 };
+                while end + 1 < lower.len()
+                    && lower[end + 1] as i32 - lower[end] as i32 == step
+                {
+                    end += 1;
+                }
+                if end - start >= 2 {
+                    matches.push(Match {
+                        pattern: "sequence",
+                        token: lower[start..=end].iter().collect(),
+                        start,
+                        end: end + 1,
+                        guesses: (end - start + 1) as f64 * 4.0,
+                    });
+                }
+            }
+        }
+
+        for row in QWERTY_ROWS {
+            let row_chars: Vec<char> = row.chars().collect();
+            for start in 0..lower.len().saturating_sub(2) {
+                let mut end = start;
+                while end + 1 < lower.len() {
+                    let current_pos = row_chars.iter().position(|c| *c == lower[end]);
+                    let next_pos = row_chars.iter().position(|c| *c == lower[end + 1]);
+                    match (current_pos, next_pos) {
+                        (Some(a), Some(b)) if (a as i32 - b as i32).abs() == 1 => end += 1,
+                        _ => break,
+                    }
+                }
+                if end - start >= 2 {
+                    matches.push(Match {
+                        pattern: "keyboard",
+                        token: lower[start..=end].iter().collect(),
+                        start,
+                        end: end + 1,
+                        guesses: (end - start + 1) as f64 * 6.0,
+                    });
+                }
+            }
+        }
+    }
+
+    // Dates: 6-8 digit runs that look like DDMMYYYY, MMDDYYYY or YYYYMMDD.
+    let digits_only: Vec<(usize, char)> = lower
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.is_ascii_digit())
+        .map(|(i, c)| (i, *c))
+        .collect();
+    for window in [6, 8] {
+        if digits_only.len() < window {
+            continue;
+        }
+        for start in 0..=(digits_only.len() - window) {
+            let run = &digits_only[start..start + window];
+            let contiguous = run.windows(2).all(|pair| pair[1].0 == pair[0].0 + 1);
+            if contiguous {
+                matches.push(Match {
+                    pattern: "date",
+                    token: run.iter().map(|(_, c)| c).collect(),
+                    start: run[0].0,
+                    end: run[run.len() - 1].0 + 1,
+                    guesses: 365.0 * 100.0,
+                });
+            }
+        }
+    }
+
+    (matches, lower.len())
+}
+
+/// Estimates the brute-force cardinality of `input`'s character set.
+fn character_cardinality(input: &str) -> f64 {
+    let mut cardinality = 0.0;
+    if input.chars().any(|c| c.is_ascii_lowercase()) {
+        cardinality += 26.0;
+    }
+    if input.chars().any(|c| c.is_ascii_uppercase()) {
+        cardinality += 26.0;
+    }
+    if input.chars().any(|c| c.is_ascii_digit()) {
+        cardinality += 10.0;
+    }
+    if input.chars().any(|c| !c.is_ascii_alphanumeric()) {
+        cardinality += 33.0;
+    }
+    cardinality.max(10.0)
+}
+
+/// Finds the cheapest way to cover `length` characters using `matches`
+/// (which may overlap), bruteforcing any characters not covered by a match.
+///
+/// This is the same idea as zxcvbn's dynamic-programming minimum-guesses
+/// pass: `best[i]` holds the lowest guess count to explain the first `i`
+/// characters, and the matches chosen to achieve it.
+fn cheapest_cover(length: usize, matches: &[Match], bruteforce_cardinality: f64) -> (f64, Vec<Match>) {
+    let mut best_guesses = vec![f64::INFINITY; length + 1];
+    let mut best_source: Vec<Option<(usize, Option<usize>)>> = vec![None; length + 1];
+    best_guesses[0] = 1.0;
+
+    for end in 1..=length {
+        // Option 1: brute-force the single character at `end - 1`.
+        let bruteforce_guesses = best_guesses[end - 1] * bruteforce_cardinality;
+        if bruteforce_guesses < best_guesses[end] {
+            best_guesses[end] = bruteforce_guesses;
+            best_source[end] = Some((end - 1, None));
+        }
+
+        // Option 2: use any match that ends exactly here.
+        for (match_index, m) in matches.iter().enumerate() {
+            if m.end != end || m.start >= end {
+                continue;
+            }
+            let candidate = best_guesses[m.start] * m.guesses.max(1.0);
+            if candidate < best_guesses[end] {
+                best_guesses[end] = candidate;
+                best_source[end] = Some((m.start, Some(match_index)));
+            }
+        }
+    }
+
+    let mut chosen = Vec::new();
+    let mut cursor = length;
+    while cursor > 0 {
+        match best_source[cursor] {
+            Some((previous, Some(match_index))) => {
+                chosen.push(matches[match_index].clone());
+                cursor = previous;
+            }
+            Some((previous, None)) => cursor = previous,
+            None => break,
+        }
+    }
+    chosen.reverse();
+
+    (best_guesses[length].max(1.0), chosen)
+}
+
+/// Converts an estimated guess count into a 0 (weakest) - 4 (strongest)
+/// score, using the same log10 guess thresholds zxcvbn does.
+fn guesses_to_score(guesses: f64) -> u8 {
+    let log10_guesses = guesses.max(1.0).log10();
+    if log10_guesses < 3.0 {
+        0
+    } else if log10_guesses < 6.0 {
+        1
+    } else if log10_guesses < 8.0 {
+        2
+    } else if log10_guesses < 10.0 {
+        3
+    } else {
+        4
+    }
+}
+
+/// Validates that `input` is at least `min_score` strong (0-4), estimating
+/// strength the way zxcvbn does rather than relying on naive length/class
+/// rules that reward "Password1!" over a long passphrase.
+pub fn validate_secret_strength(input: &str, min_score: u8) -> Result<(), ValidationError> {
+    let (matches, length) = find_matches(input);
+    let cardinality = character_cardinality(input);
+    let (guesses, weak_patterns) = cheapest_cover(length, &matches, cardinality);
+    let score = guesses_to_score(guesses);
+
+    if score < min_score {
+        let matched_patterns: Vec<String> = weak_patterns
+            .iter()
+            .map(|m| format!("{}:{}", m.pattern, m.token))
+            .collect();
+
+        return Err(ValidationError::new(
+            "secret.too_weak",
+            format!(
+                "Secret is too weak (score {} out of 4, needed at least {})",
+                score, min_score
+            ),
+        )
+        .with_param("score", score as i64)
+        .with_param("min_score", min_score as i64)
+        .with_param("guesses", guesses)
+        .with_param("matched_patterns", matched_patterns));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guesses_to_score_respects_log10_thresholds() {
+        assert_eq!(guesses_to_score(1.0), 0);
+        assert_eq!(guesses_to_score(999.0), 0);
+        assert_eq!(guesses_to_score(1_000.0), 1);
+        assert_eq!(guesses_to_score(999_999.0), 1);
+        assert_eq!(guesses_to_score(1_000_000.0), 2);
+        assert_eq!(guesses_to_score(99_999_999.0), 2);
+        assert_eq!(guesses_to_score(100_000_000.0), 3);
+        assert_eq!(guesses_to_score(9_999_999_999.0), 3);
+        assert_eq!(guesses_to_score(10_000_000_000.0), 4);
+    }
+
+    #[test]
+    fn common_passwords_score_zero() {
+        for weak in ["password", "qwerty", "aaaa1234"] {
+            assert!(
+                validate_secret_strength(weak, 1).is_err(),
+                "{:?} should have scored 0",
+                weak
+            );
+            assert!(validate_secret_strength(weak, 0).is_ok());
+        }
+    }
+
+    #[test]
+    fn long_random_passphrase_scores_at_least_three() {
+        let passphrase = "correct-horse-battery-staple-xk7q!93mzP";
+        assert!(validate_secret_strength(passphrase, 3).is_ok());
+    }
+
+    #[test]
+    fn repeats_and_sequences_are_detected() {
+        let matches = find_matches("aaaa1234").0;
+        assert!(matches.iter().any(|m| m.pattern == "repeat"));
+        assert!(matches.iter().any(|m| m.pattern == "sequence"));
+    }
+
+    #[test]
+    fn keyboard_walk_is_detected() {
+        let matches = find_matches("qwerty").0;
+        assert!(matches.iter().any(|m| m.pattern == "keyboard"));
+    }
+
+    #[test]
+    fn dictionary_match_is_detected_case_insensitively() {
+        let matches = find_matches("Password").0;
+        assert!(matches
+            .iter()
+            .any(|m| m.pattern == "dictionary" && m.token == "password"));
+    }
+
+    #[test]
+    fn date_pattern_is_detected() {
+        let matches = find_matches("20240115").0;
+        assert!(matches.iter().any(|m| m.pattern == "date"));
+    }
+
+    #[test]
+    fn cheapest_cover_length_matches_lowercased_length_even_when_casefolding_changes_it() {
+        // "İ" (Turkish dotted capital I) lowercases to two characters ("i̇"),
+        // so the match length must be taken from the lowercased copy, not
+        // from `input.chars().count()`, or `cheapest_cover` indexes out of
+        // bounds against the matches' offsets.
+        let (matches, length) = find_matches("İ");
+        let (guesses, _) = cheapest_cover(length, &matches, character_cardinality("İ"));
+        assert!(guesses >= 1.0);
+    }
+
+    #[test]
+    fn too_weak_error_reports_score_and_matched_patterns() {
+        let error = validate_secret_strength("password", 4).unwrap_err();
+        assert_eq!(error.code, "secret.too_weak");
+        assert_eq!(error.params.get("score").unwrap(), &serde_json::json!(0));
+        assert!(error.params.contains_key("matched_patterns"));
+    }
+}
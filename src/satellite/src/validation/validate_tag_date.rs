@@ -0,0 +1,53 @@
+//! Tag date validation
+//!
+//! Reputation tags are bucketed into calendar periods (year / month / day) so
+//! that scoring windows can be computed without re-parsing a timestamp.
+
+use super::ValidationError;
+
+/// A calendar period a reputation tag is filed under.
+pub struct TagDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// Validates that a [`TagDate`] describes a real calendar date.
+pub fn validate_tag_date_struct(tag_date: &TagDate) -> Result<(), ValidationError> {
+    if tag_date.month < 1 || tag_date.month > 12 {
+        return Err(ValidationError::new(
+            "tag_date.invalid_month",
+            format!("Month must be between 1 and 12, got {}", tag_date.month),
+        )
+        .with_param("month", tag_date.month as i64));
+    }
+
+    let days_in_month = match tag_date.month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            let is_leap = (tag_date.year % 4 == 0 && tag_date.year % 100 != 0)
+                || tag_date.year % 400 == 0;
+            if is_leap {
+                29
+            } else {
+                28
+            }
+        }
+        _ => unreachable!(),
+    };
+
+    if tag_date.day < 1 || tag_date.day > days_in_month {
+        return Err(ValidationError::new(
+            "tag_date.invalid_day",
+            format!(
+                "Day must be between 1 and {} for month {}, got {}",
+                days_in_month, tag_date.month, tag_date.day
+            ),
+        )
+        .with_param("day", tag_date.day as i64)
+        .with_param("days_in_month", days_in_month as i64));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+//! The `Validate` trait
+//!
+//! Implementing [`Validate`] lets a struct describe its own validation rules
+//! via `#[derive(Validate)]` instead of callers chaining the free functions
+//! in this module by hand.
+
+use super::ValidationErrors;
+
+/// A type that can validate itself, typically via `#[derive(Validate)]`.
+pub trait Validate {
+    /// Runs every field-level validator and returns the combined result.
+    ///
+    /// Returns `Ok(())` if every field passed, or an `Err` containing every
+    /// field's failures otherwise.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::Validate;
+
+    #[derive(Validate)]
+    struct Profile {
+        #[validate(handle)]
+        handle: String,
+        #[validate(display_name)]
+        display_name: String,
+        #[validate(length(min = 1, max = 10))]
+        bio: String,
+    }
+
+    #[test]
+    fn validate_accumulates_every_failing_field_instead_of_bailing_early() {
+        let profile = Profile {
+            handle: "!".to_string(),
+            display_name: "".to_string(),
+            bio: "this bio is far too long to fit".to_string(),
+        };
+
+        let errors = profile.validate().unwrap_err();
+
+        assert!(errors.0.contains_key("handle"));
+        assert!(errors.0.contains_key("display_name"));
+        assert!(errors.0.contains_key("bio"));
+    }
+
+    #[test]
+    fn validate_passes_when_every_field_is_valid() {
+        let profile = Profile {
+            handle: "jane_doe".to_string(),
+            display_name: "Jane Doe".to_string(),
+            bio: "hi there".to_string(),
+        };
+
+        assert!(profile.validate().is_ok());
+    }
+}